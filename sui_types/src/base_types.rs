@@ -0,0 +1,114 @@
+// Copyright (c) 2021, Facebook, Inc. and its affiliates
+// Copyright (c) 2022, Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use sha3::{Digest, Sha3_256};
+use std::fmt;
+
+use crate::crypto::SignatureScheme;
+
+pub type AuthorityName = PublicKeyBytes;
+
+/// The bytes of an authority or client public key, self-describing the signature scheme
+/// (see [`SignatureScheme`]) it was produced with so a committee can mix schemes on the wire.
+#[derive(Eq, PartialEq, Ord, PartialOrd, Clone, Hash, Serialize, Deserialize)]
+pub struct PublicKeyBytes {
+    pub scheme: SignatureScheme,
+    pub bytes: Vec<u8>,
+}
+
+impl PublicKeyBytes {
+    pub fn new(scheme: SignatureScheme, bytes: Vec<u8>) -> Self {
+        Self { scheme, bytes }
+    }
+}
+
+impl AsRef<[u8]> for PublicKeyBytes {
+    fn as_ref(&self) -> &[u8] {
+        &self.bytes
+    }
+}
+
+impl fmt::Debug for PublicKeyBytes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "k#{:?}:{}", self.scheme, hex::encode(&self.bytes))
+    }
+}
+
+#[derive(Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Hash, Serialize, Deserialize, Default)]
+pub struct SuiAddress([u8; 20]);
+
+impl SuiAddress {
+    pub fn new(bytes: [u8; 20]) -> Self {
+        Self(bytes)
+    }
+
+    pub fn from_public_key_bytes(key: &PublicKeyBytes) -> Self {
+        let mut hasher = Sha3_256::new();
+        hasher.update(key.as_ref());
+        let digest = hasher.finalize();
+        let mut address = [0u8; 20];
+        address.copy_from_slice(&digest[..20]);
+        Self(address)
+    }
+}
+
+impl fmt::Debug for SuiAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "0x{}", hex::encode(self.0))
+    }
+}
+
+#[derive(Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Hash, Serialize, Deserialize, Debug, Default)]
+pub struct ObjectID([u8; 20]);
+
+impl ObjectID {
+    pub fn new(bytes: [u8; 20]) -> Self {
+        Self(bytes)
+    }
+
+    pub fn random() -> Self {
+        let mut bytes = [0u8; 20];
+        rand::thread_rng().fill(&mut bytes);
+        Self(bytes)
+    }
+}
+
+#[derive(Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Hash, Serialize, Deserialize, Debug, Default)]
+pub struct SequenceNumber(u64);
+
+impl SequenceNumber {
+    pub fn new() -> Self {
+        Self(0)
+    }
+
+    pub fn increment(self) -> Self {
+        Self(self.0 + 1)
+    }
+}
+
+impl From<u64> for SequenceNumber {
+    fn from(value: u64) -> Self {
+        Self(value)
+    }
+}
+
+#[derive(Eq, PartialEq, Copy, Clone, Hash, Serialize, Deserialize, Debug, Default)]
+pub struct ObjectDigest(pub [u8; 32]);
+
+impl ObjectDigest {
+    pub fn new(bytes: [u8; 32]) -> Self {
+        Self(bytes)
+    }
+}
+
+pub type ObjectRef = (ObjectID, SequenceNumber, ObjectDigest);
+
+pub fn dbg_addr(n: u8) -> SuiAddress {
+    SuiAddress::new([n; 20])
+}
+
+pub fn dbg_object_id(n: u8) -> ObjectID {
+    ObjectID::new([n; 20])
+}
@@ -0,0 +1,77 @@
+// Copyright (c) 2021, Facebook, Inc. and its affiliates
+// Copyright (c) 2022, Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+use std::collections::HashMap;
+
+use fastcrypto::bls12381::min_sig::BLS12381PublicKey;
+use serde::{Deserialize, Serialize};
+
+use crate::base_types::PublicKeyBytes;
+use crate::crypto::{verify_proof_of_possession, ProofOfPossession};
+use crate::error::SuiError;
+
+pub type StakeUnit = u64;
+
+/// The set of authorities trusted to certify orders, along with how much stake each one
+/// carries. A caller holding only a `Committee` (no other authority state) can verify a
+/// `CertifiedOrder` by checking its signatures represent a quorum of this stake; see
+/// `CertifiedOrder::verify_quorum`.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Committee {
+    voting_rights: HashMap<PublicKeyBytes, StakeUnit>,
+    total_votes: StakeUnit,
+    /// Each authority's BLS public key, sorted by authority name into the canonical order a
+    /// `CertificateSignatures::Aggregated` certificate's `signer_bitmap` indexes into. Empty
+    /// for a committee whose authorities never produce aggregated certificates.
+    bls_authorities: Vec<(PublicKeyBytes, BLS12381PublicKey)>,
+}
+
+impl Committee {
+    pub fn new(voting_rights: HashMap<PublicKeyBytes, StakeUnit>) -> Self {
+        Committee::new_with_bls_keys(voting_rights, Vec::new())
+            .expect("an empty BLS authority list has no proofs of possession to reject")
+    }
+
+    /// Like `new`, but additionally records each authority's BLS public key so that an
+    /// aggregated `CertifiedOrder` can be quorum-verified: see `CertifiedOrder::verify_quorum`
+    /// and [`Self::bls_authorities`]. Each BLS key must come with a [`ProofOfPossession`] of
+    /// its matching private key, checked here before it is accepted: without this, an
+    /// attacker could register a rogue BLS key derived from honest authorities' public keys
+    /// and forge a valid-looking aggregated signature for a message the real committee never
+    /// signed.
+    pub fn new_with_bls_keys(
+        voting_rights: HashMap<PublicKeyBytes, StakeUnit>,
+        mut bls_authorities: Vec<(PublicKeyBytes, BLS12381PublicKey, ProofOfPossession)>,
+    ) -> Result<Self, SuiError> {
+        for (_, public_key, proof) in &bls_authorities {
+            verify_proof_of_possession(public_key, proof)?;
+        }
+        bls_authorities.sort_by(|(a, _, _), (b, _, _)| a.cmp(b));
+        let total_votes = voting_rights.values().sum();
+        let bls_authorities = bls_authorities
+            .into_iter()
+            .map(|(name, key, _)| (name, key))
+            .collect();
+        Ok(Committee { voting_rights, total_votes, bls_authorities })
+    }
+
+    pub fn weight(&self, authority: &PublicKeyBytes) -> StakeUnit {
+        *self.voting_rights.get(authority).unwrap_or(&0)
+    }
+
+    pub fn total_votes(&self) -> StakeUnit {
+        self.total_votes
+    }
+
+    /// The stake required for a certificate to be trusted: more than two thirds of
+    /// `total_votes`. With at most `f = (total_votes - 1) / 3` byzantine stake this is at
+    /// least `2f + 1` (exactly `2f + 1` when `total_votes = 3f + 1`).
+    pub fn quorum_threshold(&self) -> StakeUnit {
+        2 * self.total_votes / 3 + 1
+    }
+
+    /// Each authority's BLS public key, in the canonical order a `signer_bitmap` indexes into.
+    pub fn bls_authorities(&self) -> &[(PublicKeyBytes, BLS12381PublicKey)] {
+        &self.bls_authorities
+    }
+}
@@ -0,0 +1,315 @@
+// Copyright (c) 2021, Facebook, Inc. and its affiliates
+// Copyright (c) 2022, Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+use std::collections::HashSet;
+
+use ed25519_dalek::{Signer as _, Verifier as _};
+use fastcrypto::bls12381::min_sig::{
+    BLS12381AggregateSignature, BLS12381KeyPair, BLS12381PublicKey, BLS12381Signature,
+};
+use fastcrypto::traits::{
+    AggregateAuthenticator, KeyPair as FastCryptoKeyPair, Signer, ToFromBytes, VerifyingKey,
+};
+use k256::ecdsa::signature::DigestSigner as _;
+use k256::elliptic_curve::sec1::ToEncodedPoint;
+use rand::rngs::OsRng;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest as _, Sha256};
+
+use crate::base_types::{PublicKeyBytes, SuiAddress};
+use crate::error::SuiError;
+
+/// The signature algorithm a [`PublicKeyBytes`]/[`AuthoritySignature`] pair was produced with.
+/// Serialized alongside every key and signature on the wire (JWS-style "alg" tag) so a
+/// committee can mix schemes without a breaking wire-format change.
+#[derive(Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Hash, Debug, Serialize, Deserialize)]
+pub enum SignatureScheme {
+    Ed25519,
+    Secp256k1,
+    /// A secp256k1 ECDSA signature in the 65-byte `r‖s‖v` form, from which the signer's
+    /// public key can be recovered; see [`recover_signer`]. Lets a sender-signed `Order`
+    /// omit its sender's public key entirely.
+    Secp256k1Recoverable,
+}
+
+enum KeyPairInner {
+    Ed25519(ed25519_dalek::Keypair),
+    Secp256k1(k256::ecdsa::SigningKey),
+    Secp256k1Recoverable(k256::ecdsa::SigningKey),
+}
+
+/// A keypair for an authority or a client, tagged with the [`SignatureScheme`] it signs with.
+pub struct KeyPair {
+    inner: KeyPairInner,
+    public_key_bytes: PublicKeyBytes,
+}
+
+impl KeyPair {
+    pub fn public_key_bytes(&self) -> &PublicKeyBytes {
+        &self.public_key_bytes
+    }
+
+    pub fn sign(&self, message: &[u8]) -> AuthoritySignature {
+        match &self.inner {
+            KeyPairInner::Ed25519(keypair) => AuthoritySignature {
+                scheme: SignatureScheme::Ed25519,
+                bytes: keypair.sign(message).to_bytes().to_vec(),
+            },
+            KeyPairInner::Secp256k1(signing_key) => {
+                let signature: k256::ecdsa::Signature = signing_key.sign(message);
+                AuthoritySignature {
+                    scheme: SignatureScheme::Secp256k1,
+                    bytes: signature.to_der().as_bytes().to_vec(),
+                }
+            }
+            KeyPairInner::Secp256k1Recoverable(signing_key) => {
+                let signature: k256::ecdsa::recoverable::Signature = signing_key
+                    .try_sign_digest(Sha256::new_with_prefix(message))
+                    .expect("signing with a valid secp256k1 key cannot fail");
+                AuthoritySignature {
+                    scheme: SignatureScheme::Secp256k1Recoverable,
+                    bytes: signature.as_ref().to_vec(),
+                }
+            }
+        }
+    }
+}
+
+/// Generate an ed25519 keypair. Equivalent to `get_key_pair_with_scheme(SignatureScheme::Ed25519)`.
+pub fn get_key_pair() -> (PublicKeyBytes, KeyPair) {
+    get_key_pair_with_scheme(SignatureScheme::Ed25519)
+}
+
+pub fn get_key_pair_with_scheme(scheme: SignatureScheme) -> (PublicKeyBytes, KeyPair) {
+    match scheme {
+        SignatureScheme::Ed25519 => {
+            let keypair = ed25519_dalek::Keypair::generate(&mut rand_core_05::OsRng);
+            let public_key_bytes = PublicKeyBytes::new(scheme, keypair.public.to_bytes().to_vec());
+            (
+                public_key_bytes.clone(),
+                KeyPair { inner: KeyPairInner::Ed25519(keypair), public_key_bytes },
+            )
+        }
+        SignatureScheme::Secp256k1 => {
+            let signing_key = k256::ecdsa::SigningKey::random(&mut OsRng);
+            let verifying_key = k256::ecdsa::VerifyingKey::from(&signing_key);
+            let public_key_bytes = PublicKeyBytes::new(
+                scheme,
+                verifying_key.to_encoded_point(true).as_bytes().to_vec(),
+            );
+            (
+                public_key_bytes.clone(),
+                KeyPair { inner: KeyPairInner::Secp256k1(signing_key), public_key_bytes },
+            )
+        }
+        SignatureScheme::Secp256k1Recoverable => {
+            let signing_key = k256::ecdsa::SigningKey::random(&mut OsRng);
+            let verifying_key = k256::ecdsa::VerifyingKey::from(&signing_key);
+            let public_key_bytes = PublicKeyBytes::new(
+                scheme,
+                verifying_key.to_encoded_point(true).as_bytes().to_vec(),
+            );
+            (
+                public_key_bytes.clone(),
+                KeyPair {
+                    inner: KeyPairInner::Secp256k1Recoverable(signing_key),
+                    public_key_bytes,
+                },
+            )
+        }
+    }
+}
+
+/// A signature over the bytes of an `OrderData`, self-describing its [`SignatureScheme`] so
+/// verification can dispatch correctly without out-of-band knowledge of the signer's scheme.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct AuthoritySignature {
+    pub scheme: SignatureScheme,
+    pub bytes: Vec<u8>,
+}
+
+impl AuthoritySignature {
+    pub fn new<T: Serialize>(value: &T, signer: &KeyPair) -> Self {
+        let message = bincode::serialize(value).expect("serialization of signable value failed");
+        signer.sign(&message)
+    }
+
+    pub fn check<T: Serialize>(&self, value: &T, author: &PublicKeyBytes) -> Result<(), SuiError> {
+        let message = bincode::serialize(value).expect("serialization of signable value failed");
+        self.verify_raw(&message, author)
+    }
+
+    fn verify_raw(&self, message: &[u8], author: &PublicKeyBytes) -> Result<(), SuiError> {
+        if self.scheme != author.scheme {
+            return Err(SuiError::InvalidSignature {
+                error: "signature scheme does not match the signer's public key".to_string(),
+            });
+        }
+        match self.scheme {
+            SignatureScheme::Ed25519 => {
+                let public_key = ed25519_dalek::PublicKey::from_bytes(author.as_ref())
+                    .map_err(|e| SuiError::InvalidSignature { error: e.to_string() })?;
+                let signature = ed25519_dalek::Signature::from_bytes(&self.bytes)
+                    .map_err(|e| SuiError::InvalidSignature { error: e.to_string() })?;
+                public_key
+                    .verify(message, &signature)
+                    .map_err(|e| SuiError::InvalidSignature { error: e.to_string() })
+            }
+            SignatureScheme::Secp256k1 => {
+                let verifying_key = k256::ecdsa::VerifyingKey::from_sec1_bytes(author.as_ref())
+                    .map_err(|e| SuiError::InvalidSignature { error: e.to_string() })?;
+                let signature = k256::ecdsa::Signature::from_der(&self.bytes)
+                    .map_err(|e| SuiError::InvalidSignature { error: e.to_string() })?;
+                verifying_key
+                    .verify(message, &signature)
+                    .map_err(|e| SuiError::InvalidSignature { error: e.to_string() })
+            }
+            SignatureScheme::Secp256k1Recoverable => {
+                let recovered = recover_public_key(message, &self.bytes)?;
+                if &recovered == author {
+                    Ok(())
+                } else {
+                    Err(SuiError::InvalidSignature {
+                        error: "public key recovered from signature does not match the signer"
+                            .to_string(),
+                    })
+                }
+            }
+        }
+    }
+
+    /// Verify that every `(signer, signature)` pair is valid, one signature at a time, and that
+    /// every signer is a recognized committee member. Cost is linear in the number of signers;
+    /// [`AggregateAuthoritySignature`] avoids this for large committees by verifying a single
+    /// aggregated pairing instead.
+    pub fn verify_batch<T: Serialize>(
+        value: &T,
+        votes: &[(PublicKeyBytes, AuthoritySignature)],
+        committee_keys: &HashSet<PublicKeyBytes>,
+    ) -> Result<(), SuiError> {
+        let message = bincode::serialize(value).expect("serialization of signable value failed");
+        for (author, signature) in votes {
+            if !committee_keys.contains(author) {
+                return Err(SuiError::UnknownSigner);
+            }
+            signature.verify_raw(&message, author)?;
+        }
+        Ok(())
+    }
+}
+
+/// Recover the public key that produced a 65-byte recoverable `signature` (`r‖s‖v`) over
+/// `message`, shared by [`AuthoritySignature::verify_raw`]'s recoverable-scheme branch and
+/// [`recover_signer`].
+fn recover_public_key(message: &[u8], signature_bytes: &[u8]) -> Result<PublicKeyBytes, SuiError> {
+    let signature = k256::ecdsa::recoverable::Signature::try_from(signature_bytes)
+        .map_err(|e| SuiError::InvalidSignature { error: e.to_string() })?;
+    let verifying_key = signature
+        .recover_verifying_key_from_digest(Sha256::new_with_prefix(message))
+        .map_err(|e| SuiError::InvalidSignature { error: e.to_string() })?;
+    Ok(PublicKeyBytes::new(
+        SignatureScheme::Secp256k1Recoverable,
+        verifying_key.to_encoded_point(true).as_bytes().to_vec(),
+    ))
+}
+
+/// Recover the address of the key that produced `signature` over `value`, without needing the
+/// signer's public key out of band. Lets an `Order` signed with a
+/// [`SignatureScheme::Secp256k1Recoverable`] key omit its sender's public key entirely: the
+/// authority recomputes and trusts the sender address instead of a transmitted one.
+pub fn recover_signer<T: Serialize>(
+    value: &T,
+    signature: &AuthoritySignature,
+) -> Result<SuiAddress, SuiError> {
+    if signature.scheme != SignatureScheme::Secp256k1Recoverable {
+        return Err(SuiError::InvalidSignature {
+            error: "signature is not in recoverable form".to_string(),
+        });
+    }
+    let message = bincode::serialize(value).expect("serialization of signable value failed");
+    let public_key_bytes = recover_public_key(&message, &signature.bytes)?;
+    Ok(SuiAddress::from_public_key_bytes(&public_key_bytes))
+}
+
+/// A BLS12-381 keypair used by an authority that wants its certificate signatures to be
+/// aggregatable. Distinct from the [`KeyPair`] used for ordinary order/vote signing.
+pub struct BlsKeyPair(BLS12381KeyPair);
+
+/// A self-signature over an authority's own BLS public key, collected at committee setup to
+/// rule out rogue-key attacks against signature aggregation.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ProofOfPossession(Vec<u8>);
+
+impl BlsKeyPair {
+    pub fn generate() -> Self {
+        BlsKeyPair(BLS12381KeyPair::generate(&mut rand::thread_rng()))
+    }
+
+    pub fn public(&self) -> BLS12381PublicKey {
+        self.0.public().clone()
+    }
+
+    /// Sign our own public key, proving we know the matching private key.
+    pub fn generate_proof_of_possession(&self) -> ProofOfPossession {
+        let pk_bytes = bincode::serialize(&self.0.public()).expect("key serialization failed");
+        ProofOfPossession(self.0.sign(&pk_bytes).as_ref().to_vec())
+    }
+
+    pub fn sign_order_data<T: Serialize>(&self, value: &T) -> BLS12381Signature {
+        let message = bincode::serialize(value).expect("serialization of signable value failed");
+        self.0.sign(&message)
+    }
+}
+
+/// Verify a proof-of-possession produced by [`BlsKeyPair::generate_proof_of_possession`],
+/// guarding committee setup against rogue-key attacks on aggregated signatures.
+pub fn verify_proof_of_possession(
+    public_key: &BLS12381PublicKey,
+    proof: &ProofOfPossession,
+) -> Result<(), SuiError> {
+    let pk_bytes = bincode::serialize(public_key).expect("key serialization failed");
+    let signature = BLS12381Signature::from_bytes(&proof.0)
+        .map_err(|e| SuiError::InvalidSignature { error: e.to_string() })?;
+    public_key
+        .verify(&pk_bytes, &signature)
+        .map_err(|e| SuiError::InvalidSignature { error: e.to_string() })
+}
+
+/// A single aggregated BLS signature standing in for many authorities' individual signatures
+/// over the same `OrderData`. Which committee members (in the committee's canonical order)
+/// contributed to it is not stored here: the signer bitmap is passed to [`Self::verify`].
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct AggregateAuthoritySignature {
+    pub signature: Vec<u8>,
+}
+
+impl AggregateAuthoritySignature {
+    pub fn aggregate(signatures: &[BLS12381Signature]) -> Result<Self, SuiError> {
+        let aggregate = BLS12381AggregateSignature::aggregate(signatures)
+            .map_err(|e| SuiError::InvalidSignature { error: e.to_string() })?;
+        Ok(AggregateAuthoritySignature { signature: aggregate.as_ref().to_vec() })
+    }
+
+    /// Verify the aggregated signature against the public keys selected by `signer_bitmap`,
+    /// in `committee_keys`' canonical order. `signer_bitmap[i / 8]`'s bit `i % 8` is set iff
+    /// `committee_keys[i]` contributed a signature.
+    pub fn verify<T: Serialize>(
+        &self,
+        value: &T,
+        signer_bitmap: &[u8],
+        committee_keys: &[BLS12381PublicKey],
+    ) -> Result<(), SuiError> {
+        let message = bincode::serialize(value).expect("serialization of signable value failed");
+        let selected: Vec<BLS12381PublicKey> = committee_keys
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| signer_bitmap.get(i / 8).is_some_and(|b| b & (1 << (i % 8)) != 0))
+            .map(|(_, key)| key.clone())
+            .collect();
+        let aggregate = BLS12381AggregateSignature::from_bytes(&self.signature)
+            .map_err(|e| SuiError::InvalidSignature { error: e.to_string() })?;
+        aggregate
+            .verify(&selected, &message)
+            .map_err(|e| SuiError::InvalidSignature { error: e.to_string() })
+    }
+}
@@ -0,0 +1,25 @@
+// Copyright (c) 2021, Facebook, Inc. and its affiliates
+// Copyright (c) 2022, Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+#[derive(Eq, PartialEq, Clone, Debug, Serialize, Deserialize, Error)]
+pub enum SuiError {
+    #[error("Signer does not match any known authority")]
+    UnknownSigner,
+    #[error("The same authority signed more than once")]
+    DuplicateSigner,
+    #[error("Signature is not valid: {error}")]
+    InvalidSignature { error: String },
+    #[error("Value was not signed by the correct sender: {error}")]
+    IncorrectSigner { error: String },
+    #[error("Signatures in a certificate must form a quorum: {error}")]
+    CertificateRequiresQuorum { error: String },
+    #[error("Error serializing value: {error}")]
+    SerializationError { error: String },
+    #[error("Error deserializing value: {error}")]
+    DeserializationError { error: String },
+    #[error("Requested object does not exist")]
+    ObjectNotFound,
+}
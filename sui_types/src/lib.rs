@@ -0,0 +1,11 @@
+// Copyright (c) 2021, Facebook, Inc. and its affiliates
+// Copyright (c) 2022, Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+pub mod base_types;
+pub mod committee;
+pub mod crypto;
+pub mod error;
+pub mod messages;
+pub mod object;
+pub mod serialize;
@@ -0,0 +1,281 @@
+// Copyright (c) 2021, Facebook, Inc. and its affiliates
+// Copyright (c) 2022, Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+
+use crate::base_types::{ObjectRef, PublicKeyBytes, SequenceNumber, SuiAddress};
+use crate::committee::Committee;
+use crate::crypto::{recover_signer, AggregateAuthoritySignature, AuthoritySignature, KeyPair};
+use crate::error::SuiError;
+use crate::object::Object;
+
+#[derive(Eq, PartialEq, Clone, Debug, Serialize, Deserialize)]
+pub enum OrderKind {
+    Transfer {
+        recipient: SuiAddress,
+        object_ref: ObjectRef,
+    },
+}
+
+#[derive(Eq, PartialEq, Clone, Debug, Serialize, Deserialize)]
+pub struct OrderData {
+    pub kind: OrderKind,
+    /// `None` for orders signed with a [`crate::crypto::SignatureScheme::Secp256k1Recoverable`]
+    /// key: the sender's public key is recovered from the signature instead of being
+    /// transmitted, saving the bytes it would otherwise cost on the wire.
+    pub sender: Option<PublicKeyBytes>,
+    pub gas_payment: ObjectRef,
+}
+
+impl OrderData {
+    pub fn digest(&self) -> [u8; 32] {
+        use sha3::{Digest, Sha3_256};
+        let bytes = bincode::serialize(self).expect("order data always serializes");
+        let mut hasher = Sha3_256::new();
+        hasher.update(&bytes);
+        let mut digest = [0u8; 32];
+        digest.copy_from_slice(&hasher.finalize());
+        digest
+    }
+}
+
+#[derive(Eq, PartialEq, Clone, Debug, Serialize, Deserialize)]
+pub struct Order {
+    pub data: OrderData,
+    pub signature: AuthoritySignature,
+}
+
+impl Order {
+    pub fn new_transfer(
+        recipient: SuiAddress,
+        object_ref: ObjectRef,
+        sender: PublicKeyBytes,
+        gas_payment: ObjectRef,
+        sender_key: &KeyPair,
+    ) -> Self {
+        let data = OrderData {
+            kind: OrderKind::Transfer { recipient, object_ref },
+            sender: Some(sender),
+            gas_payment,
+        };
+        let signature = AuthoritySignature::new(&data, sender_key);
+        Order { data, signature }
+    }
+
+    /// Build a transfer order signed with a
+    /// [`crate::crypto::SignatureScheme::Secp256k1Recoverable`] key, omitting the sender's
+    /// public key from `data` since it can be recovered from the signature alone.
+    pub fn new_transfer_recoverable(
+        recipient: SuiAddress,
+        object_ref: ObjectRef,
+        gas_payment: ObjectRef,
+        sender_key: &KeyPair,
+    ) -> Self {
+        let data = OrderData {
+            kind: OrderKind::Transfer { recipient, object_ref },
+            sender: None,
+            gas_payment,
+        };
+        let signature = AuthoritySignature::new(&data, sender_key);
+        Order { data, signature }
+    }
+
+    pub fn digest(&self) -> [u8; 32] {
+        self.data.digest()
+    }
+
+    /// The address that signed this order: transmitted directly for an explicit sender, or
+    /// recomputed from the signature for a recoverable one.
+    pub fn sender_address(&self) -> Result<SuiAddress, SuiError> {
+        match &self.data.sender {
+            Some(sender) => Ok(SuiAddress::from_public_key_bytes(sender)),
+            None => recover_signer(&self.data, &self.signature),
+        }
+    }
+
+    pub fn check_signature(&self) -> Result<(), SuiError> {
+        match &self.data.sender {
+            Some(sender) => self.signature.check(&self.data, sender),
+            None => recover_signer(&self.data, &self.signature).map(|_| ()),
+        }
+    }
+}
+
+/// An authority's vote on an `Order`, i.e. a single signature towards a future `CertifiedOrder`.
+#[derive(Eq, PartialEq, Clone, Debug, Serialize, Deserialize)]
+pub struct SignedOrder {
+    pub order: Order,
+    pub authority: PublicKeyBytes,
+    pub signature: AuthoritySignature,
+}
+
+impl SignedOrder {
+    pub fn new(order: Order, authority: PublicKeyBytes, authority_key: &KeyPair) -> Self {
+        let signature = AuthoritySignature::new(&order.data, authority_key);
+        SignedOrder { order, authority, signature }
+    }
+}
+
+/// The signatures backing a `CertifiedOrder`, in either of the two wire forms this crate
+/// supports: one full signature per authority, or a single BLS aggregate plus a bitmap of
+/// which committee members contributed to it. See `crypto::AggregateAuthoritySignature`.
+#[derive(Eq, PartialEq, Clone, Debug, Serialize, Deserialize)]
+pub enum CertificateSignatures {
+    Individual(Vec<(PublicKeyBytes, AuthoritySignature)>),
+    Aggregated {
+        signer_bitmap: Vec<u8>,
+        signature: AggregateAuthoritySignature,
+    },
+}
+
+impl CertificateSignatures {
+    pub fn empty() -> Self {
+        CertificateSignatures::Individual(Vec::new())
+    }
+
+    pub fn push_individual(&mut self, authority: PublicKeyBytes, signature: AuthoritySignature) {
+        match self {
+            CertificateSignatures::Individual(votes) => votes.push((authority, signature)),
+            CertificateSignatures::Aggregated { .. } => {
+                panic!("cannot add an individual signature to an already-aggregated certificate")
+            }
+        }
+    }
+
+    pub fn individual_votes(&self) -> Option<&Vec<(PublicKeyBytes, AuthoritySignature)>> {
+        match self {
+            CertificateSignatures::Individual(votes) => Some(votes),
+            CertificateSignatures::Aggregated { .. } => None,
+        }
+    }
+}
+
+#[derive(Eq, PartialEq, Clone, Debug, Serialize, Deserialize)]
+pub struct CertifiedOrder {
+    pub order: Order,
+    pub signatures: CertificateSignatures,
+}
+
+impl CertifiedOrder {
+    /// Verify this certificate against `committee` alone, with no other authority state: every
+    /// signer must be a distinct member of the committee, every signature must check out, and
+    /// the signing stake must clear `committee.quorum_threshold()`. Distinguishes a bad
+    /// signature (`SuiError::InvalidSignature`/`UnknownSigner`/`DuplicateSigner`) from a
+    /// structurally valid certificate that simply doesn't carry enough stake
+    /// (`SuiError::CertificateRequiresQuorum`). Handles both certificate forms: one signature
+    /// per authority, and a single BLS aggregate plus a bitmap resolved against
+    /// `committee.bls_authorities()`.
+    pub fn verify_quorum(&self, committee: &Committee) -> Result<(), SuiError> {
+        match &self.signatures {
+            CertificateSignatures::Individual(votes) => {
+                let mut seen = HashSet::new();
+                let mut signing_stake = 0;
+                for (author, signature) in votes {
+                    if committee.weight(author) == 0 {
+                        return Err(SuiError::UnknownSigner);
+                    }
+                    if !seen.insert(author) {
+                        return Err(SuiError::DuplicateSigner);
+                    }
+                    signature.check(&self.order.data, author)?;
+                    signing_stake += committee.weight(author);
+                }
+                self.check_quorum_stake(committee, signing_stake)
+            }
+            CertificateSignatures::Aggregated { signer_bitmap, signature } => {
+                let bls_authorities = committee.bls_authorities();
+                let keys: Vec<_> = bls_authorities.iter().map(|(_, key)| key.clone()).collect();
+                signature.verify(&self.order.data, signer_bitmap, &keys)?;
+
+                let signing_stake: u64 = bls_authorities
+                    .iter()
+                    .enumerate()
+                    .filter(|(i, _)| {
+                        signer_bitmap.get(i / 8).is_some_and(|b| b & (1 << (i % 8)) != 0)
+                    })
+                    .map(|(_, (authority, _))| committee.weight(authority))
+                    .sum();
+                self.check_quorum_stake(committee, signing_stake)
+            }
+        }
+    }
+
+    fn check_quorum_stake(&self, committee: &Committee, signing_stake: u64) -> Result<(), SuiError> {
+        if signing_stake < committee.quorum_threshold() {
+            return Err(SuiError::CertificateRequiresQuorum {
+                error: format!(
+                    "{} stake signed, but a quorum requires {}",
+                    signing_stake,
+                    committee.quorum_threshold()
+                ),
+            });
+        }
+        Ok(())
+    }
+}
+
+#[derive(Eq, PartialEq, Clone, Debug, Serialize, Deserialize)]
+pub struct ObjectInfoRequest {
+    pub object_id: crate::base_types::ObjectID,
+    pub request_sequence_number: Option<SequenceNumber>,
+}
+
+impl ObjectInfoRequest {
+    pub fn latest_object_info_request(
+        object_id: crate::base_types::ObjectID,
+        request_sequence_number: Option<SequenceNumber>,
+    ) -> Self {
+        ObjectInfoRequest { object_id, request_sequence_number }
+    }
+
+    pub fn past_object_info_request(
+        object_id: crate::base_types::ObjectID,
+        sequence_number: SequenceNumber,
+    ) -> Self {
+        ObjectInfoRequest { object_id, request_sequence_number: Some(sequence_number) }
+    }
+}
+
+#[derive(Eq, PartialEq, Clone, Debug, Serialize, Deserialize)]
+pub struct ObjectResponse {
+    pub object: Object,
+    pub lock: Option<SignedOrder>,
+    pub layout: Option<()>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ObjectInfoResponse {
+    pub object_and_lock: Option<ObjectResponse>,
+    pub parent_certificate: Option<CertifiedOrder>,
+    pub requested_object_reference: Option<ObjectRef>,
+}
+
+impl ObjectInfoResponse {
+    pub fn object(&self) -> Result<Object, SuiError> {
+        self.object_and_lock
+            .as_ref()
+            .map(|r| r.object.clone())
+            .ok_or(SuiError::ObjectNotFound)
+    }
+
+    /// SPV-style provenance check for a thin client that trusts only `committee`: the
+    /// `parent_certificate`, if present, must carry a quorum of `committee`'s stake, and the
+    /// object's current lock, if present, must be a validly-signed vote from a committee
+    /// member. This never touches full authority state -- only this response and `committee`.
+    pub fn verify_against_committee(&self, committee: &Committee) -> Result<(), SuiError> {
+        if let Some(parent_certificate) = &self.parent_certificate {
+            parent_certificate.verify_quorum(committee)?;
+        }
+        if let Some(object_and_lock) = &self.object_and_lock {
+            if let Some(lock) = &object_and_lock.lock {
+                if committee.weight(&lock.authority) == 0 {
+                    return Err(SuiError::UnknownSigner);
+                }
+                lock.signature.check(&lock.order.data, &lock.authority)?;
+            }
+        }
+        Ok(())
+    }
+}
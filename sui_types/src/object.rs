@@ -0,0 +1,45 @@
+// Copyright (c) 2021, Facebook, Inc. and its affiliates
+// Copyright (c) 2022, Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+use serde::{Deserialize, Serialize};
+
+use crate::base_types::{ObjectDigest, ObjectID, ObjectRef, SequenceNumber, SuiAddress};
+
+#[derive(Eq, PartialEq, Clone, Debug, Serialize, Deserialize)]
+pub struct Object {
+    id: ObjectID,
+    owner: SuiAddress,
+    version: SequenceNumber,
+    contents: Vec<u8>,
+}
+
+impl Object {
+    pub fn with_id_owner_for_testing(id: ObjectID, owner: SuiAddress) -> Self {
+        Self {
+            id,
+            owner,
+            version: SequenceNumber::new(),
+            contents: Vec::new(),
+        }
+    }
+
+    pub fn id(&self) -> ObjectID {
+        self.id
+    }
+
+    pub fn owner(&self) -> SuiAddress {
+        self.owner
+    }
+
+    pub fn version(&self) -> SequenceNumber {
+        self.version
+    }
+
+    pub fn digest(&self) -> ObjectDigest {
+        ObjectDigest::new([0; 32])
+    }
+
+    pub fn to_object_reference(&self) -> ObjectRef {
+        (self.id, self.version, self.digest())
+    }
+}
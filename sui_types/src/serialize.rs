@@ -0,0 +1,248 @@
+// Copyright (c) 2021, Facebook, Inc. and its affiliates
+// Copyright (c) 2022, Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+use std::io::{Read, Write};
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::SuiError;
+use crate::messages::{CertifiedOrder, ObjectInfoRequest, ObjectInfoResponse, Order, SignedOrder};
+
+/// One byte ahead of every serialized message identifying which variant follows, so
+/// `deserialize_message` knows which type to `bincode::deserialize` next.
+#[derive(Copy, Clone)]
+enum MessageTag {
+    Error = 0,
+    Order = 1,
+    Vote = 2,
+    Cert = 3,
+    ObjectInfoReq = 4,
+    ObjectInfoResp = 5,
+    /// Same wire shape as `Order`, but the sender signed with a recoverable signature and
+    /// `OrderData::sender` is `None`; tagged separately so a caller can tell at a glance that
+    /// this order carries no redundant sender public key.
+    RecoverableOrder = 6,
+}
+
+pub enum SerializedMessage {
+    Error(Box<SuiError>),
+    Order(Box<Order>),
+    Vote(Box<SignedOrder>),
+    Cert(Box<CertifiedOrder>),
+    ObjectInfoReq(Box<ObjectInfoRequest>),
+    ObjectInfoResp(Box<ObjectInfoResponse>),
+}
+
+fn serialize_with_tag<T: Serialize>(tag: MessageTag, value: &T) -> Vec<u8> {
+    let mut buf = Vec::new();
+    write_with_tag(&mut buf, tag, value).expect("writing to a Vec<u8> cannot fail");
+    buf
+}
+
+fn write_with_tag<T: Serialize, W: Write>(
+    writer: &mut W,
+    tag: MessageTag,
+    value: &T,
+) -> Result<(), SuiError> {
+    writer
+        .write_all(&[tag as u8])
+        .map_err(|e| SuiError::SerializationError { error: e.to_string() })?;
+    bincode::serialize_into(writer, value)
+        .map_err(|e| SuiError::SerializationError { error: e.to_string() })
+}
+
+pub fn serialize_error(value: &SuiError) -> Vec<u8> {
+    serialize_with_tag(MessageTag::Error, value)
+}
+
+pub fn serialize_object_info_request(value: &ObjectInfoRequest) -> Vec<u8> {
+    serialize_with_tag(MessageTag::ObjectInfoReq, value)
+}
+
+pub fn serialize_object_info_response(value: &ObjectInfoResponse) -> Vec<u8> {
+    serialize_with_tag(MessageTag::ObjectInfoResp, value)
+}
+
+pub fn serialize_order(value: &Order) -> Vec<u8> {
+    serialize_with_tag(MessageTag::Order, value)
+}
+
+pub fn serialize_transfer_order_into<W: Write>(writer: &mut W, value: &Order) -> Result<(), SuiError> {
+    write_with_tag(writer, MessageTag::Order, value)
+}
+
+pub fn serialize_order_recoverable(value: &Order) -> Vec<u8> {
+    serialize_with_tag(MessageTag::RecoverableOrder, value)
+}
+
+pub fn serialize_transfer_order_recoverable_into<W: Write>(
+    writer: &mut W,
+    value: &Order,
+) -> Result<(), SuiError> {
+    write_with_tag(writer, MessageTag::RecoverableOrder, value)
+}
+
+pub fn serialize_vote(value: &SignedOrder) -> Vec<u8> {
+    serialize_with_tag(MessageTag::Vote, value)
+}
+
+pub fn serialize_vote_into<W: Write>(writer: &mut W, value: &SignedOrder) -> Result<(), SuiError> {
+    write_with_tag(writer, MessageTag::Vote, value)
+}
+
+pub fn serialize_cert(value: &CertifiedOrder) -> Vec<u8> {
+    serialize_with_tag(MessageTag::Cert, value)
+}
+
+pub fn serialize_cert_into<W: Write>(writer: &mut W, value: &CertifiedOrder) -> Result<(), SuiError> {
+    write_with_tag(writer, MessageTag::Cert, value)
+}
+
+pub fn deserialize_message<R: Read>(mut reader: R) -> Result<SerializedMessage, SuiError> {
+    let mut tag = [0u8; 1];
+    reader
+        .read_exact(&mut tag)
+        .map_err(|e| SuiError::DeserializationError { error: e.to_string() })?;
+
+    fn read<T: for<'de> Deserialize<'de>, R: Read>(reader: R) -> Result<T, SuiError> {
+        bincode::deserialize_from(reader).map_err(|e| SuiError::DeserializationError { error: e.to_string() })
+    }
+
+    Ok(match tag[0] {
+        t if t == MessageTag::Error as u8 => SerializedMessage::Error(Box::new(read(reader)?)),
+        t if t == MessageTag::Order as u8 || t == MessageTag::RecoverableOrder as u8 => {
+            SerializedMessage::Order(Box::new(read(reader)?))
+        }
+        t if t == MessageTag::Vote as u8 => SerializedMessage::Vote(Box::new(read(reader)?)),
+        t if t == MessageTag::Cert as u8 => SerializedMessage::Cert(Box::new(read(reader)?)),
+        t if t == MessageTag::ObjectInfoReq as u8 => {
+            SerializedMessage::ObjectInfoReq(Box::new(read(reader)?))
+        }
+        t if t == MessageTag::ObjectInfoResp as u8 => {
+            SerializedMessage::ObjectInfoResp(Box::new(read(reader)?))
+        }
+        _ => {
+            return Err(SuiError::DeserializationError {
+                error: "unknown message tag".to_string(),
+            })
+        }
+    })
+}
+
+/// Read a consensus-encoding-style VarInt (1/3/5/9 bytes: a 1-byte tag, optionally followed by
+/// a little-endian 2/4/8-byte value) from the front of `buf` without consuming anything if
+/// `buf` doesn't yet hold the full encoding. Returns the decoded value and how many bytes it
+/// occupied.
+fn try_read_varint(buf: &[u8]) -> Option<(u64, usize)> {
+    let tag = *buf.first()?;
+    match tag {
+        0..=0xfc => Some((tag as u64, 1)),
+        0xfd => Some((u16::from_le_bytes(buf.get(1..3)?.try_into().ok()?) as u64, 3)),
+        0xfe => Some((u32::from_le_bytes(buf.get(1..5)?.try_into().ok()?) as u64, 5)),
+        0xff => Some((u64::from_le_bytes(buf.get(1..9)?.try_into().ok()?), 9)),
+    }
+}
+
+fn write_varint<W: Write>(writer: &mut W, value: u64) -> Result<(), SuiError> {
+    let result = if value <= 0xfc {
+        writer.write_all(&[value as u8])
+    } else if value <= u16::MAX as u64 {
+        writer
+            .write_all(&[0xfd])
+            .and_then(|_| writer.write_all(&(value as u16).to_le_bytes()))
+    } else if value <= u32::MAX as u64 {
+        writer
+            .write_all(&[0xfe])
+            .and_then(|_| writer.write_all(&(value as u32).to_le_bytes()))
+    } else {
+        writer
+            .write_all(&[0xff])
+            .and_then(|_| writer.write_all(&value.to_le_bytes()))
+    };
+    result.map_err(|e| SuiError::SerializationError { error: e.to_string() })
+}
+
+/// Prefix `message` (the output of one of the `serialize_*` functions above) with a VarInt of
+/// its length, so a reader over a byte stream with no other record boundary (e.g. a raw TCP
+/// socket) can tell where it ends.
+pub fn write_framed_message<W: Write>(writer: &mut W, message: &[u8]) -> Result<(), SuiError> {
+    write_varint(writer, message.len() as u64)?;
+    writer
+        .write_all(message)
+        .map_err(|e| SuiError::SerializationError { error: e.to_string() })
+}
+
+/// Attempt to decode one length-prefixed message from the front of `buf`. Returns `Ok(None)`
+/// if `buf` does not yet contain a complete frame -- the caller should read more bytes from
+/// the stream and try again -- rather than a hard error, since a partial frame is expected
+/// when reading off a live connection. On success, returns the message and how many bytes of
+/// `buf` it consumed.
+pub fn read_framed_message(buf: &[u8]) -> Result<Option<(SerializedMessage, usize)>, SuiError> {
+    let (length, header_len) = match try_read_varint(buf) {
+        Some(parsed) => parsed,
+        None => return Ok(None),
+    };
+    let length = length as usize;
+    let body_end = match header_len.checked_add(length) {
+        Some(end) => end,
+        None => return Ok(None),
+    };
+    let body = match buf.get(header_len..body_end) {
+        Some(body) => body,
+        None => return Ok(None),
+    };
+    let message = deserialize_message(body)?;
+    Ok(Some((message, header_len + length)))
+}
+
+/// Pulls complete, length-framed messages out of any `Read` stream (a socket, a file, ...),
+/// buffering partial reads internally instead of erroring on a truncated frame. Pairs with
+/// [`write_framed_message`] on the writing side.
+pub struct FramedMessageReader<R> {
+    reader: R,
+    buf: Vec<u8>,
+}
+
+impl<R: Read> FramedMessageReader<R> {
+    pub fn new(reader: R) -> Self {
+        FramedMessageReader { reader, buf: Vec::new() }
+    }
+
+    /// Returns the next complete message, blocking on the underlying reader for more bytes as
+    /// needed. Returns `Ok(None)` only at a clean end-of-stream, i.e. no partial frame pending.
+    pub fn next_message(&mut self) -> Result<Option<SerializedMessage>, SuiError> {
+        loop {
+            if let Some((message, consumed)) = read_framed_message(&self.buf)? {
+                self.buf.drain(..consumed);
+                return Ok(Some(message));
+            }
+            let mut chunk = [0u8; 4096];
+            let n = self
+                .reader
+                .read(&mut chunk)
+                .map_err(|e| SuiError::DeserializationError { error: e.to_string() })?;
+            if n == 0 {
+                return if self.buf.is_empty() {
+                    Ok(None)
+                } else {
+                    Err(SuiError::DeserializationError {
+                        error: "stream ended in the middle of a framed message".to_string(),
+                    })
+                };
+            }
+            self.buf.extend_from_slice(&chunk[..n]);
+        }
+    }
+}
+
+impl<R: Read> Iterator for FramedMessageReader<R> {
+    type Item = Result<SerializedMessage, SuiError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_message().transpose()
+    }
+}
+
+#[cfg(test)]
+#[path = "unit_tests/serialize_tests.rs"]
+mod tests;
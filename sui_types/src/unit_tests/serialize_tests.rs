@@ -6,7 +6,12 @@
 use super::*;
 use crate::{
     base_types::*,
-    crypto::{get_key_pair, AuthoritySignature},
+    committee::Committee,
+    crypto::{
+        get_key_pair, get_key_pair_with_scheme, AggregateAuthoritySignature, AuthoritySignature,
+        BlsKeyPair, SignatureScheme,
+    },
+    messages::{CertificateSignatures, ObjectResponse},
     object::Object,
 };
 use std::time::Instant;
@@ -125,6 +130,35 @@ fn test_order() {
     }
 }
 
+// A recoverable-signature order carries no sender public key at all: the authority recovers
+// the sender's address straight from the signature instead of trusting a transmitted key.
+#[test]
+fn test_order_recoverable() {
+    let (_, sender_key) = get_key_pair_with_scheme(SignatureScheme::Secp256k1Recoverable);
+
+    let transfer_order = Order::new_transfer_recoverable(
+        dbg_addr(0x20),
+        random_object_ref(),
+        random_object_ref(),
+        &sender_key,
+    );
+    assert!(transfer_order.data.sender.is_none());
+
+    let buf = serialize_order_recoverable(&transfer_order);
+    let result = deserialize_message(buf.as_slice());
+    assert!(result.is_ok());
+    if let SerializedMessage::Order(o) = result.unwrap() {
+        assert!(*o == transfer_order);
+        o.check_signature().unwrap();
+        assert_eq!(
+            o.sender_address().unwrap(),
+            SuiAddress::from_public_key_bytes(sender_key.public_key_bytes())
+        );
+    } else {
+        panic!()
+    }
+}
+
 #[test]
 fn test_vote() {
     let (sender_name, sender_key) = get_key_pair();
@@ -137,7 +171,7 @@ fn test_vote() {
     );
 
     let (_, authority_key) = get_key_pair();
-    let vote = SignedOrder::new(order, *authority_key.public_key_bytes(), &authority_key);
+    let vote = SignedOrder::new(order, authority_key.public_key_bytes().clone(), &authority_key);
 
     let buf = serialize_vote(&vote);
     let result = deserialize_message(buf.as_slice());
@@ -161,7 +195,7 @@ fn test_cert() {
     );
     let mut cert = CertifiedOrder {
         order,
-        signatures: Vec::new(),
+        signatures: CertificateSignatures::empty(),
     };
 
     for _ in 0..3 {
@@ -169,9 +203,52 @@ fn test_cert() {
         let sig = AuthoritySignature::new(&cert.order.data, &authority_key);
 
         cert.signatures
-            .push((*authority_key.public_key_bytes(), sig));
+            .push_individual(authority_key.public_key_bytes().clone(), sig);
+    }
+
+    let buf = serialize_cert(&cert);
+    let result = deserialize_message(buf.as_slice());
+    assert!(result.is_ok());
+    if let SerializedMessage::Cert(o) = result.unwrap() {
+        compare_certified_orders(o.as_ref(), &cert);
+    } else {
+        panic!()
+    }
+}
+
+// An aggregated BLS certificate should round-trip just like the individual-signature form.
+#[test]
+fn test_cert_aggregated() {
+    let (sender_name, sender_key) = get_key_pair();
+    let order = Order::new_transfer(
+        dbg_addr(0x20),
+        random_object_ref(),
+        sender_name,
+        random_object_ref(),
+        &sender_key,
+    );
+
+    let committee: Vec<BlsKeyPair> = (0..3).map(|_| BlsKeyPair::generate()).collect();
+    // Each authority registers a proof-of-possession of its BLS key at committee setup,
+    // which rules out rogue-key attacks on the aggregated signature below.
+    for authority in &committee {
+        let pop = authority.generate_proof_of_possession();
+        crate::crypto::verify_proof_of_possession(&authority.public(), &pop).unwrap();
     }
 
+    let signatures: Vec<_> = committee
+        .iter()
+        .map(|authority| authority.sign_order_data(&order.data))
+        .collect();
+    let aggregate = AggregateAuthoritySignature::aggregate(&signatures).unwrap();
+    let cert = CertifiedOrder {
+        order,
+        signatures: CertificateSignatures::Aggregated {
+            signer_bitmap: vec![0b0000_0111],
+            signature: aggregate,
+        },
+    };
+
     let buf = serialize_cert(&cert);
     let result = deserialize_message(buf.as_slice());
     assert!(result.is_ok());
@@ -180,6 +257,107 @@ fn test_cert() {
     } else {
         panic!()
     }
+
+    let committee_keys: Vec<_> = committee.iter().map(|authority| authority.public()).collect();
+    match &cert.signatures {
+        CertificateSignatures::Aggregated { signer_bitmap, signature } => {
+            signature
+                .verify(&cert.order.data, signer_bitmap, &committee_keys)
+                .unwrap();
+        }
+        CertificateSignatures::Individual(_) => panic!(),
+    }
+
+    // The same aggregated certificate also quorum-verifies against a stake-weighted
+    // `Committee`, as a light client holding no other authority state would do.
+    let mut voting_rights = std::collections::HashMap::new();
+    let bls_authorities: Vec<_> = committee
+        .iter()
+        .map(|authority| {
+            let (name, _) = get_key_pair();
+            voting_rights.insert(name.clone(), 1);
+            (name, authority.public(), authority.generate_proof_of_possession())
+        })
+        .collect();
+    let weighted_committee = Committee::new_with_bls_keys(voting_rights, bls_authorities).unwrap();
+    cert.verify_quorum(&weighted_committee).unwrap();
+}
+
+// A BLS key registered with a proof-of-possession that doesn't match it -- as a rogue-key
+// attacker trying to register a key derived from an honest authority's public key would
+// produce -- must be rejected outright, not silently accepted into the committee.
+#[test]
+fn test_committee_rejects_bad_proof_of_possession() {
+    let authority = BlsKeyPair::generate();
+    let impostor = BlsKeyPair::generate();
+    let (name, _) = get_key_pair();
+    let mut voting_rights = std::collections::HashMap::new();
+    voting_rights.insert(name.clone(), 1);
+
+    // `impostor`'s proof of possession is over its own key, not `authority`'s: claiming it
+    // proves possession of `authority`'s public key must fail.
+    let bad_proof = impostor.generate_proof_of_possession();
+    let result = Committee::new_with_bls_keys(
+        voting_rights,
+        vec![(name, authority.public(), bad_proof)],
+    );
+    assert!(matches!(result, Err(SuiError::InvalidSignature { .. })));
+}
+
+// A committee can mix signature schemes: a secp256k1 authority's vote must verify against
+// its own self-describing public key without any special-casing at the call site.
+#[test]
+fn test_vote_secp256k1() {
+    let (sender_name, sender_key) = get_key_pair();
+    let order = Order::new_transfer(
+        dbg_addr(0x20),
+        random_object_ref(),
+        sender_name,
+        random_object_ref(),
+        &sender_key,
+    );
+
+    let (_, authority_key) = get_key_pair_with_scheme(SignatureScheme::Secp256k1);
+    let vote = SignedOrder::new(order, authority_key.public_key_bytes().clone(), &authority_key);
+
+    let buf = serialize_vote(&vote);
+    let result = deserialize_message(buf.as_slice());
+    assert!(result.is_ok());
+    if let SerializedMessage::Vote(o) = result.unwrap() {
+        assert!(*o == vote);
+        o.signature.check(&o.order.data, &o.authority).unwrap();
+    } else {
+        panic!()
+    }
+}
+
+// A single committee can hold authorities signing with different schemes, and `verify_batch`
+// checks every vote against its own self-describing public key without special-casing any of
+// them.
+#[test]
+fn test_verify_batch_heterogeneous_committee() {
+    let (sender_name, sender_key) = get_key_pair();
+    let order = Order::new_transfer(
+        dbg_addr(0x20),
+        random_object_ref(),
+        sender_name,
+        random_object_ref(),
+        &sender_key,
+    );
+
+    let (_, ed25519_key) = get_key_pair_with_scheme(SignatureScheme::Ed25519);
+    let (_, secp256k1_key) = get_key_pair_with_scheme(SignatureScheme::Secp256k1);
+    let (_, recoverable_key) = get_key_pair_with_scheme(SignatureScheme::Secp256k1Recoverable);
+
+    let mut committee_keys = std::collections::HashSet::new();
+    let mut votes = Vec::new();
+    for authority_key in [&ed25519_key, &secp256k1_key, &recoverable_key] {
+        committee_keys.insert(authority_key.public_key_bytes().clone());
+        let sig = AuthoritySignature::new(&order.data, authority_key);
+        votes.push((authority_key.public_key_bytes().clone(), sig));
+    }
+
+    AuthoritySignature::verify_batch(&order.data, &votes, &committee_keys).unwrap();
 }
 
 #[test]
@@ -194,11 +372,11 @@ fn test_info_response() {
     );
 
     let (_, auth_key) = get_key_pair();
-    let vote = SignedOrder::new(order.clone(), *auth_key.public_key_bytes(), &auth_key);
+    let vote = SignedOrder::new(order.clone(), auth_key.public_key_bytes().clone(), &auth_key);
 
     let mut cert = CertifiedOrder {
         order,
-        signatures: Vec::new(),
+        signatures: CertificateSignatures::empty(),
     };
 
     for _ in 0..3 {
@@ -206,7 +384,7 @@ fn test_info_response() {
         let sig = AuthoritySignature::new(&cert.order.data, &authority_key);
 
         cert.signatures
-            .push((*authority_key.public_key_bytes(), sig));
+            .push_individual(authority_key.public_key_bytes().clone(), sig);
     }
 
     let object = Object::with_id_owner_for_testing(dbg_object_id(0x20), dbg_addr(0x20));
@@ -235,6 +413,45 @@ fn test_info_response() {
     }
 }
 
+// A truncated frame should ask for more bytes rather than error, and a reader built on top of
+// a plain `Read` stream should recover every message once the stream has been fully written.
+#[test]
+fn test_framed_messages() {
+    let (sender_name, sender_key) = get_key_pair();
+
+    let mut framed = Vec::new();
+    let mut orders = Vec::new();
+    let mut first_frame_len = 0;
+    for _ in 0..5 {
+        let order = Order::new_transfer(
+            dbg_addr(0x20),
+            random_object_ref(),
+            sender_name.clone(),
+            random_object_ref(),
+            &sender_key,
+        );
+        write_framed_message(&mut framed, &serialize_order(&order)).unwrap();
+        if orders.is_empty() {
+            first_frame_len = framed.len();
+        }
+        orders.push(order);
+    }
+
+    // A buffer holding less than the first frame's length prefix needs more bytes.
+    assert!(read_framed_message(&framed[..1]).unwrap().is_none());
+    // A buffer holding the length prefix but not the whole body also needs more bytes.
+    assert!(read_framed_message(&framed[..first_frame_len - 1]).unwrap().is_none());
+
+    let mut reader = FramedMessageReader::new(framed.as_slice());
+    for expected in &orders {
+        match reader.next_message().unwrap().unwrap() {
+            SerializedMessage::Order(order) => assert_eq!(*order, *expected),
+            _ => panic!(),
+        }
+    }
+    assert!(reader.next_message().unwrap().is_none());
+}
+
 #[test]
 fn test_time_order() {
     let (sender_name, sender_key) = get_key_pair();
@@ -245,7 +462,7 @@ fn test_time_order() {
         let transfer_order = Order::new_transfer(
             dbg_addr(0x20),
             random_object_ref(),
-            sender_name,
+            sender_name.clone(),
             random_object_ref(),
             &sender_key,
         );
@@ -285,7 +502,7 @@ fn test_time_vote() {
     for _ in 0..100 {
         let vote = SignedOrder::new(
             order.clone(),
-            *authority_key.public_key_bytes(),
+            authority_key.public_key_bytes().clone(),
             &authority_key,
         );
         serialize_vote_into(&mut buf, &vote).unwrap();
@@ -297,7 +514,7 @@ fn test_time_vote() {
     for _ in 0..100 {
         if let SerializedMessage::Vote(vote) = deserialize_message(&mut buf2).unwrap() {
             vote.signature
-                .check(&vote.order.data, vote.authority)
+                .check(&vote.order.data, &vote.authority)
                 .unwrap();
         }
     }
@@ -321,21 +538,17 @@ fn test_time_cert() {
     );
     let mut cert = CertifiedOrder {
         order,
-        signatures: Vec::new(),
+        signatures: CertificateSignatures::empty(),
     };
 
-    use std::collections::HashMap;
-    let mut cache = HashMap::new();
+    use std::collections::HashSet;
+    let mut cache = HashSet::new();
     for _ in 0..7 {
         let (_, authority_key) = get_key_pair();
         let sig = AuthoritySignature::new(&cert.order.data, &authority_key);
         cert.signatures
-            .push((*authority_key.public_key_bytes(), sig));
-        cache.insert(
-            *authority_key.public_key_bytes(),
-            ed25519_dalek::PublicKey::from_bytes(authority_key.public_key_bytes().as_ref())
-                .expect("No problem parsing key."),
-        );
+            .push_individual(authority_key.public_key_bytes().clone(), sig);
+        cache.insert(authority_key.public_key_bytes().clone());
     }
 
     let mut buf = Vec::new();
@@ -350,7 +563,8 @@ fn test_time_cert() {
     let mut buf2 = buf.as_slice();
     for _ in 0..count {
         if let SerializedMessage::Cert(cert) = deserialize_message(&mut buf2).unwrap() {
-            AuthoritySignature::verify_batch(&cert.order.data, &cert.signatures, &cache).unwrap();
+            let votes = cert.signatures.individual_votes().unwrap();
+            AuthoritySignature::verify_batch(&cert.order.data, votes, &cache).unwrap();
         }
     }
     assert!(deserialize_message(buf2).is_err());
@@ -358,4 +572,189 @@ fn test_time_cert() {
         "Read & Quickcheck Cert: {} microsec",
         now.elapsed().as_micros() / count
     );
-}
\ No newline at end of file
+}
+
+// The aggregated counterpart of `test_time_cert`, at the same 7-signer scale: replacing N
+// individual signatures with one aggregate should mean a wire size and verification cost that
+// no longer grow linearly in the number of signers.
+#[test]
+fn test_time_cert_aggregated() {
+    let count = 100;
+    let (sender_name, sender_key) = get_key_pair();
+    let order = Order::new_transfer(
+        dbg_addr(0x20),
+        random_object_ref(),
+        sender_name,
+        random_object_ref(),
+        &sender_key,
+    );
+
+    let authorities: Vec<BlsKeyPair> = (0..7).map(|_| BlsKeyPair::generate()).collect();
+    let committee_keys: Vec<_> = authorities.iter().map(|authority| authority.public()).collect();
+    let signatures: Vec<_> = authorities
+        .iter()
+        .map(|authority| authority.sign_order_data(&order.data))
+        .collect();
+    let aggregate = AggregateAuthoritySignature::aggregate(&signatures).unwrap();
+    let cert = CertifiedOrder {
+        order,
+        signatures: CertificateSignatures::Aggregated {
+            signer_bitmap: vec![0b0111_1111],
+            signature: aggregate,
+        },
+    };
+
+    let mut buf = Vec::new();
+    let now = Instant::now();
+    for _ in 0..count {
+        serialize_cert_into(&mut buf, &cert).unwrap();
+    }
+    println!(
+        "Write Aggregated Cert: {} microsec",
+        now.elapsed().as_micros() / count
+    );
+
+    let now = Instant::now();
+    let mut buf2 = buf.as_slice();
+    for _ in 0..count {
+        if let SerializedMessage::Cert(cert) = deserialize_message(&mut buf2).unwrap() {
+            match &cert.signatures {
+                CertificateSignatures::Aggregated { signer_bitmap, signature } => {
+                    signature
+                        .verify(&cert.order.data, signer_bitmap, &committee_keys)
+                        .unwrap();
+                }
+                CertificateSignatures::Individual(_) => panic!(),
+            }
+        }
+    }
+    assert!(deserialize_message(buf2).is_err());
+    println!(
+        "Read & Quickcheck Aggregated Cert: {} microsec",
+        now.elapsed().as_micros() / count
+    );
+}
+
+// A certificate with too little signing stake is rejected even though every individual
+// signature is valid, and the error distinguishes that case from an outright bad signature.
+#[test]
+fn test_verify_quorum() {
+    let (sender_name, sender_key) = get_key_pair();
+    let order = Order::new_transfer(
+        dbg_addr(0x20),
+        random_object_ref(),
+        sender_name,
+        random_object_ref(),
+        &sender_key,
+    );
+    let mut cert = CertifiedOrder {
+        order,
+        signatures: CertificateSignatures::empty(),
+    };
+
+    let mut voting_rights = std::collections::HashMap::new();
+    let mut authority_keys = Vec::new();
+    for _ in 0..4 {
+        let (_, authority_key) = get_key_pair();
+        voting_rights.insert(authority_key.public_key_bytes().clone(), 1);
+        authority_keys.push(authority_key);
+    }
+    let committee = Committee::new(voting_rights);
+    assert_eq!(committee.quorum_threshold(), 3);
+
+    // Two out of four authorities (2 stake) do not clear the quorum threshold (3 stake).
+    for authority_key in &authority_keys[..2] {
+        let sig = AuthoritySignature::new(&cert.order.data, authority_key);
+        cert.signatures
+            .push_individual(authority_key.public_key_bytes().clone(), sig);
+    }
+    assert!(matches!(
+        cert.verify_quorum(&committee),
+        Err(SuiError::CertificateRequiresQuorum { .. })
+    ));
+
+    // A third valid signature clears the quorum.
+    let sig = AuthoritySignature::new(&cert.order.data, &authority_keys[2]);
+    cert.signatures
+        .push_individual(authority_keys[2].public_key_bytes().clone(), sig);
+    cert.verify_quorum(&committee).unwrap();
+
+    // A signer outside the committee is rejected outright, regardless of stake.
+    let (_, outsider_key) = get_key_pair();
+    let mut cert_with_outsider = cert.clone();
+    let sig = AuthoritySignature::new(&cert_with_outsider.order.data, &outsider_key);
+    cert_with_outsider
+        .signatures
+        .push_individual(outsider_key.public_key_bytes().clone(), sig);
+    assert!(matches!(
+        cert_with_outsider.verify_quorum(&committee),
+        Err(SuiError::UnknownSigner)
+    ));
+
+    // The same authority signing twice is rejected outright, regardless of stake.
+    let mut cert_with_duplicate = cert.clone();
+    let sig = AuthoritySignature::new(&cert_with_duplicate.order.data, &authority_keys[0]);
+    cert_with_duplicate
+        .signatures
+        .push_individual(authority_keys[0].public_key_bytes().clone(), sig);
+    assert!(matches!(
+        cert_with_duplicate.verify_quorum(&committee),
+        Err(SuiError::DuplicateSigner)
+    ));
+}
+
+// A thin client holding only a `Committee` should be able to confirm an object's provenance
+// from an `ObjectInfoResponse` alone, the way SPV checks a header against known difficulty.
+#[test]
+fn test_light_client_verification() {
+    let (sender_name, sender_key) = get_key_pair();
+    let order = Order::new_transfer(
+        dbg_addr(0x20),
+        random_object_ref(),
+        sender_name,
+        random_object_ref(),
+        &sender_key,
+    );
+
+    let mut voting_rights = std::collections::HashMap::new();
+    let mut authority_keys = Vec::new();
+    for _ in 0..4 {
+        let (_, authority_key) = get_key_pair();
+        voting_rights.insert(authority_key.public_key_bytes().clone(), 1);
+        authority_keys.push(authority_key);
+    }
+    let committee = Committee::new(voting_rights);
+
+    let mut cert = CertifiedOrder {
+        order: order.clone(),
+        signatures: CertificateSignatures::empty(),
+    };
+    for authority_key in &authority_keys[..3] {
+        let sig = AuthoritySignature::new(&cert.order.data, authority_key);
+        cert.signatures
+            .push_individual(authority_key.public_key_bytes().clone(), sig);
+    }
+
+    let vote = SignedOrder::new(order, authority_keys[0].public_key_bytes().clone(), &authority_keys[0]);
+    let object = Object::with_id_owner_for_testing(dbg_object_id(0x20), dbg_addr(0x20));
+    let response = ObjectInfoResponse {
+        object_and_lock: Some(ObjectResponse {
+            object: object.clone(),
+            lock: Some(vote),
+            layout: None,
+        }),
+        parent_certificate: Some(cert),
+        requested_object_reference: Some(object.to_object_reference()),
+    };
+
+    response.verify_against_committee(&committee).unwrap();
+
+    let mut response_without_quorum = response.clone();
+    response_without_quorum.parent_certificate = Some(CertifiedOrder {
+        order: response.parent_certificate.as_ref().unwrap().order.clone(),
+        signatures: CertificateSignatures::empty(),
+    });
+    assert!(response_without_quorum
+        .verify_against_committee(&committee)
+        .is_err());
+}